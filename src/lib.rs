@@ -0,0 +1,6 @@
+//! A push-parsing HTTP/1.x request and response parser.
+//!
+//! See [`parser`] for requests and [`response`] for responses.
+
+pub mod parser;
+pub mod response;
@@ -1,28 +1,37 @@
-mod parser;
+use http_parser::parser::*;
 
-use parser::*;
-fn main() -> Result<(), parser::ParsingError> {
+/// Unwrap a `ParseStatus`, assuming the buffer passed to `start` already
+/// contains the whole request (as it does in this demo).
+fn expect_complete<C, P>(status: ParseStatus<C, P>) -> C {
+    match status {
+        ParseStatus::Complete(next) => next,
+        ParseStatus::Partial(_) => panic!("packet is not fully buffered"),
+    }
+}
+
+fn main() -> Result<(), http_parser::parser::ParsingError> {
     let packet = "POST /cgi-bin/process.cgi HTTP/1.1\r
 User-Agent: Mozilla/4.0 (compatible; MSIE5.01; Windows NT)\r
 Host: www.tutorialspoint.com\r
 Content-Type: application/x-www-form-urlencoded\r
-Content-Length: length\r
+Content-Length: 49\r
 Accept-Language: en-us\r
 Accept-Encoding: gzip, deflate\r
 Connection: Keep-Alive\r
 \r
 licenseID=string&content=string&/paramsXML=string";
-    let parser = HttpRequestParser::<RequestLine<Method>>::start(packet);
+    let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, ParserConfig::default());
     println!("{:#?}", parser);
-    let parser = parser.parse()?;
+    let parser = expect_complete(parser.parse()?);
     println!("{:#?}", parser);
-    let parser = parser.parse()?;
+    let parser = expect_complete(parser.parse()?);
     println!("{:#?}", parser);
-    let parser = parser.parse()?;
+    let parser = expect_complete(parser.parse()?);
     println!("{:#?}", parser);
-    let parser = parser.parse()?;
+    let parser = expect_complete(parser.parse()?);
     println!("{:#?}", parser);
-    let request = parser.parse();
+    let (request, rest) = expect_complete(parser.parse()?);
     println!("{:#?}", request);
+    println!("trailing bytes (e.g. a pipelined request): {:?}", rest);
     Ok(())
 }
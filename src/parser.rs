@@ -1,17 +1,18 @@
 use private::SealedRequestParserState;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use thiserror::Error;
 
 #[doc(hidden)]
-const SPACE: u8 = ' ' as u8;
+pub(crate) const SPACE: u8 = b' ';
 #[doc(hidden)]
-const COLON: u8 = ':' as u8;
+pub(crate) const COLON: u8 = b':';
 #[doc(hidden)]
-const CR: u8 = '\r' as u8;
+const CR: u8 = b'\r';
 #[doc(hidden)]
-const LF: u8 = '\n' as u8;
+const LF: u8 = b'\n';
 #[doc(hidden)]
-const TAB: u8 = '\t' as u8;
+const TAB: u8 = b'\t';
 
 type Result<T> = std::result::Result<T, ParsingError>;
 
@@ -44,8 +45,61 @@ pub struct Request<'a> {
     method: &'a str,
     request_uri: &'a str,
     http_version: &'a str,
-    header: HashMap<&'a str, &'a str>,
-    body: &'a str,
+    header: Headers<'a>,
+    body: MessageBody<'a>,
+}
+
+/// An ordered collection of header fields.
+///
+/// Unlike a `HashMap`, this preserves duplicate fields (e.g. repeated
+/// `Set-Cookie` headers) in arrival order, and [`Headers::get`] /
+/// [`Headers::get_all`] compare names ASCII-case-insensitively without
+/// allocating, per RFC 7230 §3.2.
+#[derive(Debug, Default)]
+pub struct Headers<'a> {
+    entries: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Headers<'a> {
+    pub(crate) fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Append a header field, keeping any existing fields with the same name.
+    pub(crate) fn push(&mut self, name: &'a str, value: &'a str) {
+        self.entries.push((name, value));
+    }
+
+    /// The first value of the header named `name`, compared
+    /// case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.get_all(name).next()
+    }
+
+    /// All values of the header named `name`, compared case-insensitively,
+    /// in arrival order.
+    pub fn get_all<'h>(&'h self, name: &'h str) -> impl Iterator<Item = &'a str> + 'h {
+        self.entries
+            .iter()
+            .filter(move |(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+    }
+
+    /// All header fields, in arrival order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.entries.iter().copied()
+    }
+}
+
+/// The decoded body of a request or response.
+///
+/// Stays zero-copy (`Borrowed`) for the common unchunked path; becomes
+/// `Owned` when `Transfer-Encoding: chunked` required concatenating
+/// non-contiguous chunk payloads.
+#[derive(Debug, Clone)]
+pub enum MessageBody<'a> {
+    Borrowed(&'a str),
+    Owned(String),
 }
 
 // #[derive(Debug)]
@@ -67,12 +121,154 @@ impl<'a> Request<'a> {
             method: "",
             request_uri: "",
             http_version: "",
-            header: HashMap::new(),
-            body: "",
+            header: Headers::new(),
+            body: MessageBody::Borrowed(""),
+        }
+    }
+
+    /// The request's headers, in arrival order.
+    pub fn headers(&self) -> &Headers<'a> {
+        &self.header
+    }
+
+    /// The raw request-target, exactly as it appeared in the request line.
+    pub fn request_uri(&self) -> &'a str {
+        self.request_uri
+    }
+
+    /// The path component of the request-target, i.e. everything before the
+    /// first `?`.
+    pub fn path(&self) -> &'a str {
+        match self.request_uri.find('?') {
+            Some(idx) => &self.request_uri[..idx],
+            None => self.request_uri,
+        }
+    }
+
+    /// The raw query-string component of the request-target, i.e. everything
+    /// after the first `?`, not including it. Returns `None` if there is no
+    /// `?` in the request-target.
+    pub fn query(&self) -> Option<&'a str> {
+        self.request_uri
+            .find('?')
+            .map(|idx| &self.request_uri[idx + 1..])
+    }
+
+    /// Parse [`Request::query`] into percent-decoded key/value pairs, split
+    /// on `&` and `=`. A pair with no `=` is treated as having an empty
+    /// value. Returns an empty map if there is no query string.
+    pub fn query_params(&self) -> Result<HashMap<Cow<'a, str>, Cow<'a, str>>> {
+        let query = match self.query() {
+            Some(query) => query,
+            None => return Ok(HashMap::new()),
+        };
+        let mut params = HashMap::new();
+        for pair in query.split('&') {
+            if pair.is_empty() {
+                continue;
+            }
+            let (key, value) = match pair.split_once('=') {
+                Some((key, value)) => (key, value),
+                None => (pair, ""),
+            };
+            params.insert(percent_decode(key)?, percent_decode(value)?);
         }
+        Ok(params)
+    }
+
+    /// Convert this request into an [`http::Request`], mapping the method,
+    /// request-target, version, headers, and body into their `http` crate
+    /// equivalents so downstream servers and middleware built on `http` can
+    /// consume it directly.
+    #[cfg(feature = "http")]
+    pub fn to_http_request(&self) -> Result<http::Request<MessageBody<'a>>> {
+        let method = http::Method::from_bytes(self.method.as_bytes())
+            .map_err(|e| ParsingError::HttpMethodConversion(e.to_string()))?;
+        let uri = self
+            .request_uri
+            .parse::<http::Uri>()
+            .map_err(|e| ParsingError::HttpUriConversion(e.to_string()))?;
+        let version = match self.http_version {
+            "HTTP/1" | "HTTP/1.0" => http::Version::HTTP_10,
+            "HTTP/1.1" => http::Version::HTTP_11,
+            "HTTP/2" => http::Version::HTTP_2,
+            other => return Err(ParsingError::HttpVersionConversion(other.to_string())),
+        };
+
+        let mut builder = http::Request::builder().method(method).uri(uri).version(version);
+        for (name, value) in self.header.iter() {
+            let name = http::header::HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| ParsingError::HttpHeaderConversion(e.to_string()))?;
+            let value = http::header::HeaderValue::from_str(value)
+                .map_err(|e| ParsingError::HttpHeaderConversion(e.to_string()))?;
+            builder = builder.header(name, value);
+        }
+
+        builder
+            .body(self.body.clone())
+            .map_err(|e| ParsingError::HttpHeaderConversion(e.to_string()))
+    }
+}
+
+/// Percent-decode a query-string component, keeping the common case
+/// (no `%` escapes) borrowed from the original request buffer.
+fn percent_decode(input: &str) -> Result<Cow<'_, str>> {
+    if !input.as_bytes().contains(&b'%') {
+        return Ok(Cow::Borrowed(input));
+    }
+
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut curr = 0;
+    while curr < bytes.len() {
+        if bytes[curr] == b'%' {
+            if curr + 2 >= bytes.len() {
+                return Err(ParsingError::InvalidPercentEncoding);
+            }
+            let hi = hex_value(bytes[curr + 1]).ok_or(ParsingError::InvalidPercentEncoding)?;
+            let lo = hex_value(bytes[curr + 2]).ok_or(ParsingError::InvalidPercentEncoding)?;
+            decoded.push(hi * 16 + lo);
+            curr += 3;
+        } else {
+            decoded.push(bytes[curr]);
+            curr += 1;
+        }
+    }
+    String::from_utf8(decoded)
+        .map(Cow::Owned)
+        .map_err(|_| ParsingError::InvalidQuery)
+}
+
+/// Maps an ASCII hex digit to its numeric value.
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
     }
 }
 
+/// The outcome of attempting to advance a parser through a single state.
+///
+/// Borrowed from `httparse`'s push-parsing model: a state may be handed a
+/// buffer that does not yet contain everything it needs (a TCP read that
+/// splits mid-request-line, mid-header, and so on), in which case it should
+/// report that more bytes are needed rather than panicking or erroring out.
+#[derive(Debug)]
+pub enum ParseStatus<C, P> {
+    /// The state finished parsing; `C` is the parser (or request) for the next state.
+    Complete(C),
+    /// Not enough bytes were available to finish this state yet.
+    ///
+    /// `P` is this same state's parser, positioned after whatever it could
+    /// already consume (the `Header` state keeps the complete header lines
+    /// it parsed). Feed it more bytes (e.g. after another socket read),
+    /// starting at its `remaining()` bytes, via
+    /// [`HttpRequestParser::resume`] and call `parse` again.
+    Partial(P),
+}
+
 /// The provides the means of state transition for the parser,
 /// it provides a single function `parse`,
 /// when called it is supposed to parse the stream until the completion of the current state.
@@ -101,6 +297,7 @@ where
 {
     packet: &'a str,
     request: Request<'a>,
+    config: ParserConfig,
     state: S,
 }
 
@@ -108,33 +305,56 @@ impl<'a, T> HttpRequestParser<'a, T>
 where
     T: RequestParserState,
 {
+    /// Re-attach a longer buffer to a parser that previously came back as
+    /// [`ParseStatus::Partial`], so parsing can resume where it left off.
+    ///
+    /// `packet` must start at the same position as the bytes already held by
+    /// this parser (typically a longer slice into the same connection read
+    /// buffer, now that more data has arrived) -- passing unrelated data
+    /// produces nonsense rather than an error. Use [`Self::remaining`] to read
+    /// that position back out before growing the buffer, since a state can
+    /// consume several lines (e.g. several header fields) before returning
+    /// `Partial`, shifting it past where the caller last resumed from.
+    pub fn resume(self, packet: &'a str) -> Self {
+        Self { packet, ..self }
+    }
+
+    /// The unconsumed tail of the buffer this parser is currently positioned
+    /// at, i.e. the exact bytes a subsequent [`Self::resume`] call needs its
+    /// `packet` argument to start with.
+    pub fn remaining(&self) -> &'a str {
+        self.packet
+    }
+
     /// Skip existing spaces (other whitespace is not considered).
     fn skip_spaces(&mut self) {
-        let mut curr = 0;
-        let bytes = self.packet.as_bytes();
-        while curr < bytes.len() && bytes[curr] == SPACE {
-            curr += 1;
-        }
-        self.packet = &self.packet[curr..];
+        self.packet = skip_spaces(self.packet);
     }
 
-    /// If the next two characters are
-    fn skip_crlf(&mut self) {
+    /// Scan the request-target, stopping at `SP`.
+    ///
+    /// Returns `Ok(None)` without consuming anything if `SP` does not appear
+    /// in the buffered bytes yet, which the caller should treat as "not
+    /// enough data yet" rather than an error. Bytes outside the permitted
+    /// URI character class fail fast with `ParsingError::InvalidUri`.
+    fn try_parse_uri(&mut self) -> Result<Option<&'a str>> {
         let bytes = self.packet.as_bytes();
-        if is_crlf(&[bytes[0], bytes[1]]) {
-            self.packet = &self.packet[2..];
-        }
-    }
-
-    fn parse_until_char(&mut self, chr: u8) -> &'a str {
         let mut curr = 0;
-        let bytes = self.packet.as_bytes();
-        while curr < bytes.len() && bytes[curr] != chr {
+        while curr < bytes.len() && bytes[curr] != SPACE {
+            if !is_uri_char(bytes[curr]) {
+                return Err(ParsingError::InvalidUri(bytes[curr]));
+            }
+            if curr >= self.config.max_uri_length {
+                return Err(ParsingError::UriTooLong);
+            }
             curr += 1;
         }
+        if curr >= bytes.len() {
+            return Ok(None);
+        }
         let res = &self.packet[..curr];
         self.packet = &self.packet[curr..];
-        res
+        Ok(Some(res))
     }
 }
 
@@ -154,29 +374,60 @@ pub struct RequestLine<S> {
 impl<S> RequestParserState for RequestLine<S> {}
 
 impl<'a, S> HttpRequestParser<'a, RequestLine<S>> {
-    pub fn start(packet: &'a str) -> HttpRequestParser<'a, RequestLine<Method>> {
+    pub fn start(packet: &'a str, config: ParserConfig) -> HttpRequestParser<'a, RequestLine<Method>> {
         HttpRequestParser {
             packet,
             request: Request::new(),
+            config,
             state: RequestLine { state: Method },
         }
     }
 }
 
+/// Configurable limits enforced while parsing a request, guarding against
+/// unbounded memory growth from a malicious or buggy peer.
+///
+/// Constructed with [`ParserConfig::default`] for generous, finite defaults.
+#[derive(Debug, Clone, Copy)]
+pub struct ParserConfig {
+    /// Maximum length, in bytes, of the request-target.
+    pub max_uri_length: usize,
+    /// Maximum number of headers accepted in a single request.
+    pub max_headers: usize,
+    /// Maximum length, in bytes, of a single header line (name and value).
+    pub max_header_line_length: usize,
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self {
+            max_uri_length: 8 * 1024,
+            max_headers: 100,
+            max_header_line_length: 8 * 1024,
+        }
+    }
+}
+
 type RequestLineParser<'a, S> = HttpRequestParser<'a, RequestLine<S>>;
 
 #[derive(Debug)]
 pub struct Method;
 
 impl<'a> Parse for RequestLineParser<'a, Method> {
-    type NextState = Result<RequestLineParser<'a, Uri>>;
+    type NextState = Result<ParseStatus<RequestLineParser<'a, Uri>, Self>>;
 
     fn parse(mut self) -> Self::NextState {
-        let mut curr = 0;
         let bytes = self.packet.as_bytes();
-        while bytes[curr] != SPACE {
+        let mut curr = 0;
+        while curr < bytes.len() && bytes[curr] != SPACE {
+            if !is_token(bytes[curr]) {
+                return Err(ParsingError::InvalidToken(bytes[curr]));
+            }
             curr += 1;
         }
+        if curr >= bytes.len() {
+            return Ok(ParseStatus::Partial(self));
+        }
         let method = &self.packet[0..curr];
         if !is_valid_method(method) {
             return Err(ParsingError::InvalidMethod(method.to_string()));
@@ -184,11 +435,12 @@ impl<'a> Parse for RequestLineParser<'a, Method> {
         self.request.method = method;
         self.packet = &self.packet[curr + 1..];
         self.skip_spaces();
-        Ok(HttpRequestParser {
+        Ok(ParseStatus::Complete(HttpRequestParser {
             packet: self.packet,
             request: self.request,
+            config: self.config,
             state: RequestLine { state: Uri },
-        })
+        }))
     }
 }
 
@@ -196,16 +448,21 @@ impl<'a> Parse for RequestLineParser<'a, Method> {
 pub struct Uri;
 
 impl<'a> Parse for RequestLineParser<'a, Uri> {
-    type NextState = Result<RequestLineParser<'a, Version>>;
+    type NextState = Result<ParseStatus<RequestLineParser<'a, Version>, Self>>;
 
     fn parse(mut self) -> Self::NextState {
-        self.request.request_uri = self.parse_until_char(SPACE);
+        let request_uri = match self.try_parse_uri()? {
+            Some(request_uri) => request_uri,
+            None => return Ok(ParseStatus::Partial(self)),
+        };
+        self.request.request_uri = request_uri;
         self.skip_spaces();
-        Ok(HttpRequestParser {
+        Ok(ParseStatus::Complete(HttpRequestParser {
             packet: self.packet,
             request: self.request,
+            config: self.config,
             state: RequestLine { state: Version },
-        })
+        }))
     }
 }
 
@@ -213,79 +470,147 @@ impl<'a> Parse for RequestLineParser<'a, Uri> {
 pub struct Version;
 
 impl<'a> Parse for RequestLineParser<'a, Version> {
-    type NextState = Result<HttpRequestParser<'a, Header>>;
+    type NextState = Result<ParseStatus<HttpRequestParser<'a, Header>, Self>>;
 
     fn parse(mut self) -> Self::NextState {
-        let mut curr = 0;
         let bytes = self.packet.as_bytes();
-        while !is_crlf(&[bytes[curr], bytes[curr + 1]]) {
+        let mut curr = 0;
+        while curr + 1 < bytes.len() && !is_crlf(&[bytes[curr], bytes[curr + 1]]) {
             curr += 1;
         }
+        if curr + 1 >= bytes.len() {
+            return Ok(ParseStatus::Partial(self));
+        }
         let version = &self.packet[..curr];
         if !is_valid_version(version) {
             return Err(ParsingError::InvalidVersion(version.to_string()));
         }
         self.request.http_version = version;
         self.packet = &self.packet[curr + 2..];
-        Ok(HttpRequestParser {
+        Ok(ParseStatus::Complete(HttpRequestParser {
             packet: self.packet,
             request: self.request,
-            state: Header,
-        })
+            config: self.config,
+            state: Header::new(),
+        }))
     }
 }
 
-/// The `Header` state, this state should be reached *after* the `RequestLine` state.
+/// The `Header` state, this state should be reached *after* the `RequestLine`
+/// (or, for a response, the `StatusLine`) state.
 #[derive(Debug)]
-pub struct Header;
+pub struct Header {
+    /// Number of headers parsed so far, checked against `ParserConfig::max_headers`.
+    pub(crate) header_count: usize,
+}
+
+impl Header {
+    pub(crate) fn new() -> Self {
+        Self { header_count: 0 }
+    }
+}
 
 impl RequestParserState for Header {}
 
 impl<'a> HttpRequestParser<'a, Header> {
-    fn parse_line(&mut self) {
-        // Parse the line key
-        let mut curr = 0;
-        let bytes = self.packet.as_bytes();
-        while !is_whitespace(bytes[curr]) && bytes[curr] != COLON {
-            curr += 1;
+    /// Attempt to parse a single `name: value CRLF` header line.
+    ///
+    /// Returns `Ok(true)` if a full line was consumed, `Ok(false)` if the
+    /// buffer does not yet hold a full line (the caller should wait for more
+    /// bytes before retrying), or `Err` if the line is malformed.
+    fn try_parse_line(&mut self) -> Result<bool> {
+        match try_parse_header_line(self.packet, &self.config)? {
+            Some((key, value, rest)) => {
+                self.packet = rest;
+                self.request.header.push(key, value);
+                Ok(true)
+            }
+            None => Ok(false),
         }
-        let key = &self.packet[0..curr];
-        self.packet = &self.packet[curr..];
+    }
+}
 
-        // Skip the separator which will match the regex `\s*:\s*`
-        let mut curr = 0;
-        let bytes = self.packet.as_bytes();
-        while is_whitespace(bytes[curr]) || bytes[curr] == COLON {
-            curr += 1;
-        }
-        self.packet = &self.packet[curr..];
+/// Attempt to parse a single `name: value CRLF` header line out of `packet`,
+/// honoring `config`'s header-line-length limit. Shared by the request and
+/// response `Header` states, since both frame headers identically per
+/// RFC 7230 §3.2.
+///
+/// Returns `Ok(Some((name, value, rest)))` if a full line was consumed,
+/// `Ok(None)` if the buffer does not yet hold a full line (the caller should
+/// wait for more bytes before retrying), or `Err` if the line is malformed.
+pub(crate) fn try_parse_header_line<'a>(
+    packet: &'a str,
+    config: &ParserConfig,
+) -> Result<Option<(&'a str, &'a str, &'a str)>> {
+    let bytes = packet.as_bytes();
 
-        // Parse the line value
-        let bytes = self.packet.as_bytes();
-        while bytes.len() >= 2 && !is_crlf(&[bytes[curr], bytes[curr + 1]]) {
-            curr += 1;
+    // Find the line key.
+    let mut key_end = 0;
+    while key_end < bytes.len() && !is_whitespace(bytes[key_end]) && bytes[key_end] != COLON {
+        if !is_token(bytes[key_end]) {
+            return Err(ParsingError::InvalidHeaderName(bytes[key_end]));
         }
-        let value = &self.packet[0..curr];
-        self.packet = &self.packet[curr + 2..];
+        if key_end >= config.max_header_line_length {
+            return Err(ParsingError::HeaderLineTooLong);
+        }
+        key_end += 1;
+    }
+    if key_end >= bytes.len() {
+        return Ok(None);
+    }
 
-        self.request.header.insert(key, value);
+    // Skip the separator: every whitespace and `:` byte after the key, so
+    // the colon is not required and may repeat.
+    let mut value_start = key_end;
+    while value_start < bytes.len()
+        && (is_whitespace(bytes[value_start]) || bytes[value_start] == COLON)
+    {
+        value_start += 1;
     }
+
+    // Find the line value's terminating CRLF.
+    let mut curr = value_start;
+    while curr + 1 < bytes.len() && !is_crlf(&[bytes[curr], bytes[curr + 1]]) {
+        if curr >= config.max_header_line_length {
+            return Err(ParsingError::HeaderLineTooLong);
+        }
+        curr += 1;
+    }
+    if curr + 1 >= bytes.len() {
+        return Ok(None);
+    }
+
+    let key = &packet[0..key_end];
+    let value = &packet[value_start..curr];
+    let rest = &packet[curr + 2..];
+    Ok(Some((key, value, rest)))
 }
 
 impl<'a> Parse for HttpRequestParser<'a, Header> {
-    type NextState = HttpRequestParser<'a, Body>;
+    type NextState = Result<ParseStatus<HttpRequestParser<'a, Body>, Self>>;
 
     fn parse(mut self) -> Self::NextState {
-        let mut bytes = self.packet.as_bytes();
-        while bytes.len() >= 2 && !is_crlf(&[bytes[0], bytes[1]]) {
-            self.parse_line();
-            bytes = self.packet.as_bytes();
-        }
-        self.skip_crlf();
-        Self::NextState {
-            packet: self.packet,
-            request: self.request,
-            state: Body,
+        loop {
+            let bytes = self.packet.as_bytes();
+            if bytes.len() < 2 {
+                return Ok(ParseStatus::Partial(self));
+            }
+            if is_crlf(&[bytes[0], bytes[1]]) {
+                self.packet = &self.packet[2..];
+                return Ok(ParseStatus::Complete(HttpRequestParser {
+                    packet: self.packet,
+                    request: self.request,
+                    config: self.config,
+                    state: Body,
+                }));
+            }
+            if self.state.header_count >= self.config.max_headers {
+                return Err(ParsingError::TooManyHeaders);
+            }
+            if !self.try_parse_line()? {
+                return Ok(ParseStatus::Partial(self));
+            }
+            self.state.header_count += 1;
         }
     }
 }
@@ -297,14 +622,145 @@ pub struct Body;
 impl RequestParserState for Body {}
 
 impl<'a> Parse for HttpRequestParser<'a, Body> {
-    type NextState = Request<'a>;
+    /// `(Request<'a>, &'a str)`: the parsed request, plus whatever bytes
+    /// follow it in the buffer -- the start of a pipelined request, or
+    /// empty if none remain. Feed the remainder into a fresh
+    /// [`HttpRequestParser::start`] to parse it.
+    type NextState = Result<ParseStatus<(Request<'a>, &'a str), Self>>;
 
     fn parse(mut self) -> Self::NextState {
-        self.request.body = self.packet;
-        self.request
+        match frame_body(&self.request.header, self.packet)? {
+            ParseStatus::Complete((body, rest)) => {
+                self.request.body = body;
+                Ok(ParseStatus::Complete((self.request, rest)))
+            }
+            ParseStatus::Partial(()) => Ok(ParseStatus::Partial(self)),
+        }
     }
 }
 
+/// Frame a message body according to its already-parsed headers: decode a
+/// `Transfer-Encoding: chunked` body, take exactly `Content-Length` bytes, or
+/// -- if neither header is present -- treat the remainder of `packet` as the
+/// whole body. Shared by the request and response `Body` states, since both
+/// are framed identically per RFC 7230 §3.3.
+///
+/// Returns the decoded body plus whatever bytes follow it -- the start of a
+/// pipelined message, or empty if none remain.
+pub(crate) fn frame_body<'a>(
+    header: &Headers<'a>,
+    packet: &'a str,
+) -> Result<ParseStatus<(MessageBody<'a>, &'a str), ()>> {
+    if is_chunked(header) {
+        return match decode_chunked(packet)? {
+            Some((body, rest)) => Ok(ParseStatus::Complete((MessageBody::Owned(body), rest))),
+            None => Ok(ParseStatus::Partial(())),
+        };
+    }
+
+    if let Some(content_length) = content_length(header)? {
+        if packet.len() < content_length {
+            return Ok(ParseStatus::Partial(()));
+        }
+        if !packet.is_char_boundary(content_length) {
+            return Err(ParsingError::InvalidContentLength);
+        }
+        let body = &packet[..content_length];
+        let rest = &packet[content_length..];
+        return Ok(ParseStatus::Complete((MessageBody::Borrowed(body), rest)));
+    }
+
+    // No Content-Length and no chunked encoding: there is no framing that
+    // tells us where this body ends, so the whole remainder is consumed as
+    // the body and no pipelined message can follow.
+    Ok(ParseStatus::Complete((MessageBody::Borrowed(packet), "")))
+}
+
+/// Checks whether `Transfer-Encoding: chunked` is present.
+fn is_chunked(header: &Headers) -> bool {
+    header
+        .get("Transfer-Encoding")
+        .map(|value| value.trim().eq_ignore_ascii_case("chunked"))
+        .unwrap_or(false)
+}
+
+/// Parses the `Content-Length` header, if present.
+fn content_length(header: &Headers) -> Result<Option<usize>> {
+    match header.get("Content-Length") {
+        Some(value) => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| ParsingError::InvalidContentLength),
+        None => Ok(None),
+    }
+}
+
+/// Decode a `Transfer-Encoding: chunked` body.
+///
+/// Returns `Ok(Some((body, rest)))` once the terminating zero-size chunk and
+/// any trailer headers have been consumed, `Ok(None)` if the buffer does not
+/// yet hold a full chunk (the caller should wait for more bytes), or `Err` on
+/// a malformed chunk-size line.
+fn decode_chunked(packet: &str) -> Result<Option<(String, &str)>> {
+    let mut body = Vec::new();
+    let mut rest = packet;
+    loop {
+        let (size_line, after_size_line) = match try_parse_until_crlf(rest) {
+            Some(parts) => parts,
+            None => return Ok(None),
+        };
+        let size_str = size_line.split(';').next().unwrap_or("").trim();
+        let size = usize::from_str_radix(size_str, 16).map_err(|_| ParsingError::InvalidChunkSize)?;
+
+        if size == 0 {
+            return match consume_trailers(after_size_line)? {
+                Some(rest) => Ok(Some((body_to_string(body)?, rest))),
+                None => Ok(None),
+            };
+        }
+
+        let data = after_size_line.as_bytes();
+        let size_with_crlf = size.checked_add(2).ok_or(ParsingError::InvalidChunkSize)?;
+        if data.len() < size_with_crlf {
+            return Ok(None);
+        }
+        if !is_crlf(&[data[size], data[size + 1]]) {
+            return Err(ParsingError::InvalidChunkSize);
+        }
+        if !after_size_line.is_char_boundary(size_with_crlf) {
+            return Err(ParsingError::InvalidChunkSize);
+        }
+        body.extend_from_slice(&data[..size]);
+        rest = &after_size_line[size_with_crlf..];
+    }
+}
+
+/// Consume (and discard) the optional trailer headers following the
+/// zero-size chunk, up to and including the final blank-line `CRLF`.
+fn consume_trailers(packet: &str) -> Result<Option<&str>> {
+    let mut rest = packet;
+    loop {
+        let bytes = rest.as_bytes();
+        if bytes.len() < 2 {
+            return Ok(None);
+        }
+        if is_crlf(&[bytes[0], bytes[1]]) {
+            return Ok(Some(&rest[2..]));
+        }
+        rest = match try_parse_until_crlf(rest) {
+            Some((_, after_line)) => after_line,
+            None => return Ok(None),
+        };
+    }
+}
+
+/// Converts decoded chunk bytes into the owned message body, failing if the
+/// concatenated bytes are not valid UTF-8.
+fn body_to_string(bytes: Vec<u8>) -> std::result::Result<String, ParsingError> {
+    String::from_utf8(bytes).map_err(|_| ParsingError::InvalidChunkSize)
+}
+
 /// Checks if the given string slice is a valid HTTP method according to
 /// IETF RFC 2616 [5.1.1](https://tools.ietf.org/html/rfc2616#section-5.1.1).
 ///
@@ -318,21 +774,18 @@ impl<'a> Parse for HttpRequestParser<'a, Body> {
 /// - `TRACE`
 /// - `CONNECT`
 fn is_valid_method(method: &str) -> bool {
-    match method {
-        "OPTIONS" | "GET" | "HEAD" | "POST" | "PUT" | "DELETE" | "TRACE" | "CONNECT" => true,
-        _ => false,
-    }
+    matches!(
+        method,
+        "OPTIONS" | "GET" | "HEAD" | "POST" | "PUT" | "DELETE" | "TRACE" | "CONNECT"
+    )
 }
 
 /// Checks if the HTTP version is a valid version.
 ///
 /// Versions considered valid are:
 /// `HTTP/1`, `HTTP/1.0`, `HTTP/1.1`, `HTTP/2`
-fn is_valid_version(version: &str) -> bool {
-    match version {
-        "HTTP/1" | "HTTP/1.0" | "HTTP/1.1" | "HTTP/2" => true,
-        _ => false,
-    }
+pub(crate) fn is_valid_version(version: &str) -> bool {
+    matches!(version, "HTTP/1" | "HTTP/1.0" | "HTTP/1.1" | "HTTP/2")
 }
 
 /// Errors types for the parser.
@@ -342,16 +795,467 @@ pub enum ParsingError {
     InvalidMethod(String),
     #[error("invalid HTTP version: {0}")]
     InvalidVersion(String),
+    #[error("invalid token byte: {0:#04x}")]
+    InvalidToken(u8),
+    #[error("invalid URI byte: {0:#04x}")]
+    InvalidUri(u8),
+    #[error("invalid header name byte: {0:#04x}")]
+    InvalidHeaderName(u8),
+    #[error("invalid percent-encoding in query string")]
+    InvalidPercentEncoding,
+    #[error("query string is not valid UTF-8 once decoded")]
+    InvalidQuery,
+    #[error("request-target exceeds the configured maximum length")]
+    UriTooLong,
+    #[error("request has more headers than the configured maximum")]
+    TooManyHeaders,
+    #[error("header line exceeds the configured maximum length")]
+    HeaderLineTooLong,
+    #[error("invalid HTTP status code: {0}")]
+    InvalidStatusCode(String),
+    #[error("invalid chunk size in chunked transfer-encoding")]
+    InvalidChunkSize,
+    #[error("invalid Content-Length header")]
+    InvalidContentLength,
+    #[cfg(feature = "http")]
+    #[error("could not convert method into an `http::Method`: {0}")]
+    HttpMethodConversion(String),
+    #[cfg(feature = "http")]
+    #[error("could not convert request-target into an `http::Uri`: {0}")]
+    HttpUriConversion(String),
+    #[cfg(feature = "http")]
+    #[error("could not convert HTTP version into an `http::Version`: {0}")]
+    HttpVersionConversion(String),
+    #[cfg(feature = "http")]
+    #[error("could not convert header into `http` crate types: {0}")]
+    HttpHeaderConversion(String),
+}
+
+/// Checks if `b` is a `tchar` as defined by RFC 7230 §3.2.6
+/// <https://tools.ietf.org/html/rfc7230#section-3.2.6>, the character class
+/// allowed in HTTP methods and header names.
+const fn is_tchar(b: u8) -> bool {
+    matches!(
+        b,
+        b'!' | b'#'
+            | b'$'
+            | b'%'
+            | b'&'
+            | b'\''
+            | b'*'
+            | b'+'
+            | b'-'
+            | b'.'
+            | b'^'
+            | b'_'
+            | b'`'
+            | b'|'
+            | b'~'
+    ) || b.is_ascii_alphanumeric()
+}
+
+/// Checks if `b` may appear unescaped in a request-target, i.e. any visible
+/// ASCII character other than `SP` and `DEL`.
+const fn is_request_target_char(b: u8) -> bool {
+    b > 0x20 && b < 0x7F
+}
+
+/// Lookup table for [`is_tchar`], indexed by byte value.
+const TOKEN_TABLE: [bool; 256] = {
+    let mut table = [false; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = is_tchar(i as u8);
+        i += 1;
+    }
+    table
+};
+
+/// Lookup table for [`is_request_target_char`], indexed by byte value.
+const URI_TABLE: [bool; 256] = {
+    let mut table = [false; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = is_request_target_char(i as u8);
+        i += 1;
+    }
+    table
+};
+
+/// Checks if `b` is a valid HTTP token byte (method, header name).
+#[inline(always)]
+pub(crate) fn is_token(b: u8) -> bool {
+    TOKEN_TABLE[b as usize]
+}
+
+/// Checks if `b` is a valid request-target byte.
+#[inline(always)]
+fn is_uri_char(b: u8) -> bool {
+    URI_TABLE[b as usize]
 }
 
 /// Check if a pair of bytes are CRLF.
 #[inline(always)]
-fn is_crlf(bytes: &[u8; 2]) -> bool {
-    return bytes[0] == CR && bytes[1] == LF;
+pub(crate) fn is_crlf(bytes: &[u8; 2]) -> bool {
+    bytes[0] == CR && bytes[1] == LF
 }
 
 /// Check if a byte is whitespace.
 #[inline(always)]
-fn is_whitespace(byte: u8) -> bool {
-    return byte == SPACE || byte == LF || byte == CR || byte == TAB;
+pub(crate) fn is_whitespace(byte: u8) -> bool {
+    byte == SPACE || byte == LF || byte == CR || byte == TAB
+}
+
+/// Skip existing spaces (other whitespace is not considered).
+pub(crate) fn skip_spaces(packet: &str) -> &str {
+    let mut curr = 0;
+    let bytes = packet.as_bytes();
+    while curr < bytes.len() && bytes[curr] == SPACE {
+        curr += 1;
+    }
+    &packet[curr..]
+}
+
+/// Scan `packet` for `SP`, returning the slice before it and the slice
+/// starting at it. Returns `None` if `SP` does not appear yet, which the
+/// caller should treat as "not enough data yet" rather than an error.
+pub(crate) fn try_parse_until_space(packet: &str) -> Option<(&str, &str)> {
+    let bytes = packet.as_bytes();
+    let mut curr = 0;
+    while curr < bytes.len() && bytes[curr] != SPACE {
+        curr += 1;
+    }
+    if curr >= bytes.len() {
+        return None;
+    }
+    Some((&packet[..curr], &packet[curr..]))
+}
+
+/// Scan `packet` for `CRLF`, returning the slice before it and the slice
+/// following it. Returns `None` if `CRLF` does not appear yet, which the
+/// caller should treat as "not enough data yet" rather than an error.
+pub(crate) fn try_parse_until_crlf(packet: &str) -> Option<(&str, &str)> {
+    let bytes = packet.as_bytes();
+    let mut curr = 0;
+    while curr + 1 < bytes.len() && !is_crlf(&[bytes[curr], bytes[curr + 1]]) {
+        curr += 1;
+    }
+    if curr + 1 >= bytes.len() {
+        return None;
+    }
+    Some((&packet[..curr], &packet[curr + 2..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_to_header(packet: &str) -> HttpRequestParser<'_, Header> {
+        let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, ParserConfig::default());
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected method to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected uri to be complete"),
+        };
+        match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected version to be complete"),
+        }
+    }
+
+    fn parse_headers(packet: &str) -> HttpRequestParser<'_, Body> {
+        match parse_to_header(packet).parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected headers to be complete"),
+        }
+    }
+
+    #[test]
+    fn invalid_method_byte_is_rejected() {
+        let packet = "G\x01T / HTTP/1.1\r\n\r\n";
+        let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, ParserConfig::default());
+        let err = parser.parse().unwrap_err();
+        assert!(matches!(err, ParsingError::InvalidToken(0x01)));
+    }
+
+    #[test]
+    fn unknown_method_is_rejected() {
+        let packet = "FROB / HTTP/1.1\r\n\r\n";
+        let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, ParserConfig::default());
+        let err = parser.parse().unwrap_err();
+        assert!(matches!(err, ParsingError::InvalidMethod(method) if method == "FROB"));
+    }
+
+    #[test]
+    fn invalid_uri_byte_is_rejected() {
+        let packet = "GET /foo\x01bar HTTP/1.1\r\n\r\n";
+        let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, ParserConfig::default());
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected method to be complete"),
+        };
+        let err = parser.parse().unwrap_err();
+        assert!(matches!(err, ParsingError::InvalidUri(0x01)));
+    }
+
+    #[test]
+    fn invalid_header_name_byte_is_rejected() {
+        let packet = "GET / HTTP/1.1\r\nHo\x01st: example.com\r\n\r\n";
+        let err = parse_to_header(packet).parse().unwrap_err();
+        assert!(matches!(err, ParsingError::InvalidHeaderName(0x01)));
+    }
+
+    #[test]
+    fn content_length_splitting_a_multibyte_char_is_rejected_not_panicked() {
+        // "é" is 2 UTF-8 bytes; `Content-Length: 1` asks for a slice that
+        // lands in the middle of it, which must error rather than panic.
+        let packet = "GET / HTTP/1.1\r\nContent-Length: 1\r\n\r\né extra";
+        let parser = parse_headers(packet);
+        let err = parser.parse().unwrap_err();
+        assert!(matches!(err, ParsingError::InvalidContentLength));
+    }
+
+    #[test]
+    fn decode_chunked_concatenates_chunk_payloads() {
+        let packet = "4\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\nGET / HTTP/1.1\r\n\r\n";
+        let (body, rest) = decode_chunked(packet).unwrap().unwrap();
+        assert_eq!(body, "Wikipedia");
+        assert_eq!(rest, "GET / HTTP/1.1\r\n\r\n");
+    }
+
+    #[test]
+    fn decode_chunked_reports_partial_on_truncated_chunk() {
+        let packet = "4\r\nWik";
+        assert!(decode_chunked(packet).unwrap().is_none());
+    }
+
+    #[test]
+    fn decode_chunked_rejects_a_chunk_size_that_would_overflow_instead_of_panicking() {
+        // `size + 2` must not wrap around when a crafted chunk-size line
+        // parses to (near) `usize::MAX`.
+        let packet = "ffffffffffffffff\r\nX";
+        let err = decode_chunked(packet).unwrap_err();
+        assert!(matches!(err, ParsingError::InvalidChunkSize));
+    }
+
+    #[test]
+    fn partial_header_resumes_from_the_offset_remaining_reports() {
+        // The header section is split mid-line; a socket caller has no
+        // offset of its own and must rely on `remaining()` to know exactly
+        // which bytes to re-send via `resume()`.
+        let packet = "GET /foo HTTP/1.1\r\nHost: exa";
+        let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, ParserConfig::default());
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected method to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected uri to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected version to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(_) => panic!("expected headers to be partial"),
+            ParseStatus::Partial(parser) => parser,
+        };
+        assert_eq!(parser.remaining(), "Host: exa");
+
+        let parser = parser.resume("Host: example.com\r\n\r\n");
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected headers to be complete"),
+        };
+        let (request, rest) = match parser.parse().unwrap() {
+            ParseStatus::Complete(outcome) => outcome,
+            ParseStatus::Partial(_) => panic!("expected body to be complete"),
+        };
+        assert_eq!(request.request_uri(), "/foo");
+        assert_eq!(request.headers().get("Host"), Some("example.com"));
+        assert_eq!(rest, "");
+    }
+
+    #[test]
+    fn body_parse_returns_the_pipelined_bytes_following_a_content_length_body() {
+        let packet = "POST / HTTP/1.1\r\nContent-Length: 5\r\n\r\nhelloGET /next HTTP/1.1\r\n\r\n";
+        let parser = parse_headers(packet);
+        let (request, rest) = match parser.parse().unwrap() {
+            ParseStatus::Complete(outcome) => outcome,
+            ParseStatus::Partial(_) => panic!("expected body to be complete"),
+        };
+        assert!(matches!(request.body, MessageBody::Borrowed("hello")));
+        assert_eq!(rest, "GET /next HTTP/1.1\r\n\r\n");
+    }
+
+    fn parse_request(packet: &str) -> Request<'_> {
+        match parse_headers(packet).parse().unwrap() {
+            ParseStatus::Complete((request, _)) => request,
+            ParseStatus::Partial(_) => panic!("expected body to be complete"),
+        }
+    }
+
+    #[test]
+    fn path_is_everything_before_the_first_question_mark() {
+        let request = parse_request("GET /foo/bar?a=1 HTTP/1.1\r\n\r\n");
+        assert_eq!(request.path(), "/foo/bar");
+        assert_eq!(request.query(), Some("a=1"));
+    }
+
+    #[test]
+    fn path_is_the_whole_uri_when_there_is_no_query_string() {
+        let request = parse_request("GET /foo/bar HTTP/1.1\r\n\r\n");
+        assert_eq!(request.path(), "/foo/bar");
+        assert_eq!(request.query(), None);
+    }
+
+    #[test]
+    fn query_params_percent_decodes_keys_and_values() {
+        let request = parse_request("GET /search?q=hello%20world&empty HTTP/1.1\r\n\r\n");
+        let params = request.query_params().unwrap();
+        assert_eq!(params.get("q").map(|v| v.as_ref()), Some("hello world"));
+        assert_eq!(params.get("empty").map(|v| v.as_ref()), Some(""));
+    }
+
+    #[test]
+    fn query_params_is_empty_when_there_is_no_query_string() {
+        let request = parse_request("GET /foo HTTP/1.1\r\n\r\n");
+        assert!(request.query_params().unwrap().is_empty());
+    }
+
+    #[test]
+    fn query_params_rejects_a_truncated_percent_escape() {
+        let request = parse_request("GET /foo?a=10%2 HTTP/1.1\r\n\r\n");
+        let err = request.query_params().unwrap_err();
+        assert!(matches!(err, ParsingError::InvalidPercentEncoding));
+    }
+
+    #[test]
+    fn query_params_rejects_a_non_hex_percent_escape() {
+        let request = parse_request("GET /foo?a=10%zz HTTP/1.1\r\n\r\n");
+        let err = request.query_params().unwrap_err();
+        assert!(matches!(err, ParsingError::InvalidPercentEncoding));
+    }
+
+    #[test]
+    fn uri_longer_than_the_configured_limit_is_rejected() {
+        let config = ParserConfig {
+            max_uri_length: 4,
+            ..ParserConfig::default()
+        };
+        let packet = "GET /this-uri-is-too-long HTTP/1.1\r\n\r\n";
+        let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, config);
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected method to be complete"),
+        };
+        let err = parser.parse().unwrap_err();
+        assert!(matches!(err, ParsingError::UriTooLong));
+    }
+
+    #[test]
+    fn more_headers_than_the_configured_limit_is_rejected() {
+        let config = ParserConfig {
+            max_headers: 1,
+            ..ParserConfig::default()
+        };
+        let packet = "GET / HTTP/1.1\r\nA: 1\r\nB: 2\r\n\r\n";
+        let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, config);
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected method to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected uri to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected version to be complete"),
+        };
+        let err = parser.parse().unwrap_err();
+        assert!(matches!(err, ParsingError::TooManyHeaders));
+    }
+
+    #[test]
+    fn header_line_longer_than_the_configured_limit_is_rejected() {
+        let config = ParserConfig {
+            max_header_line_length: 4,
+            ..ParserConfig::default()
+        };
+        let packet = "GET / HTTP/1.1\r\nX-Long-Header: value\r\n\r\n";
+        let parser = HttpRequestParser::<RequestLine<Method>>::start(packet, config);
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected method to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected uri to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected version to be complete"),
+        };
+        let err = parser.parse().unwrap_err();
+        assert!(matches!(err, ParsingError::HeaderLineTooLong));
+    }
+
+    #[test]
+    fn headers_get_compares_names_case_insensitively() {
+        let request = parse_request("GET / HTTP/1.1\r\nContent-Type: text/plain\r\n\r\n");
+        assert_eq!(request.headers().get("content-type"), Some("text/plain"));
+        assert_eq!(request.headers().get("CONTENT-TYPE"), Some("text/plain"));
+    }
+
+    #[test]
+    fn headers_get_all_returns_every_value_for_repeated_headers_in_order() {
+        let request = parse_request("GET / HTTP/1.1\r\nSet-Cookie: a=1\r\nSet-Cookie: b=2\r\n\r\n");
+        let values: Vec<&str> = request.headers().get_all("set-cookie").collect();
+        assert_eq!(values, vec!["a=1", "b=2"]);
+        assert_eq!(request.headers().get("Set-Cookie"), Some("a=1"));
+    }
+
+    #[test]
+    fn headers_get_returns_none_for_a_missing_header() {
+        let request = parse_request("GET / HTTP/1.1\r\n\r\n");
+        assert_eq!(request.headers().get("X-Missing"), None);
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn to_http_request_maps_method_uri_version_headers_and_body() {
+        let request = parse_request("POST /foo?a=1 HTTP/1.1\r\nX-Test: value\r\n\r\nbody");
+        let http_request = request.to_http_request().unwrap();
+        assert_eq!(http_request.method(), http::Method::POST);
+        assert_eq!(http_request.uri(), "/foo?a=1");
+        assert_eq!(http_request.version(), http::Version::HTTP_11);
+        assert_eq!(http_request.headers().get("X-Test").unwrap(), "value");
+        assert!(matches!(http_request.body(), MessageBody::Borrowed("body")));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn to_http_request_rejects_a_uri_http_cannot_parse() {
+        // `<` is outside our own request-target character class's
+        // restrictions (none -- any visible ASCII is allowed), but `http::Uri`
+        // rejects it.
+        let request = parse_request("GET /a<b HTTP/1.1\r\n\r\n");
+        let err = request.to_http_request().unwrap_err();
+        assert!(matches!(err, ParsingError::HttpUriConversion(_)));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn to_http_request_rejects_a_header_value_http_cannot_represent() {
+        // Header values are unrestricted by our own parser (any byte other
+        // than CRLF), but `http::HeaderValue` rejects control bytes.
+        let request = parse_request("GET / HTTP/1.1\r\nX-Test: a\x01b\r\n\r\n");
+        let err = request.to_http_request().unwrap_err();
+        assert!(matches!(err, ParsingError::HttpHeaderConversion(_)));
+    }
 }
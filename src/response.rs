@@ -0,0 +1,348 @@
+use crate::parser::{
+    frame_body, is_crlf, is_valid_version, skip_spaces, try_parse_header_line, try_parse_until_crlf,
+    try_parse_until_space, Body, Header, Headers, MessageBody, Parse, ParseStatus, ParserConfig,
+    ParsingError,
+};
+
+type Result<T> = std::result::Result<T, ParsingError>;
+
+#[doc(hidden)]
+mod private {
+    pub trait SealedResponseParserState {}
+
+    impl<S> SealedResponseParserState for super::StatusLine<S> {}
+    impl SealedResponseParserState for super::Header {}
+    impl SealedResponseParserState for super::Body {}
+}
+
+/// The HTTP response structure, mirroring [`crate::parser::Request`].
+#[derive(Debug)]
+pub struct Response<'a> {
+    http_version: &'a str,
+    status_code: u16,
+    reason_phrase: &'a str,
+    header: Headers<'a>,
+    body: MessageBody<'a>,
+}
+
+impl<'a> Response<'a> {
+    /// Create a new `Response`.
+    fn new() -> Self {
+        Self {
+            http_version: "",
+            status_code: 0,
+            reason_phrase: "",
+            header: Headers::new(),
+            body: MessageBody::Borrowed(""),
+        }
+    }
+
+    /// The response's headers, in arrival order.
+    pub fn headers(&self) -> &Headers<'a> {
+        &self.header
+    }
+
+    /// The HTTP version of the status line, e.g. `HTTP/1.1`.
+    pub fn http_version(&self) -> &'a str {
+        self.http_version
+    }
+
+    /// The numeric status code of the status line, e.g. `200`.
+    pub fn status_code(&self) -> u16 {
+        self.status_code
+    }
+
+    /// The reason phrase of the status line, e.g. `OK`.
+    pub fn reason_phrase(&self) -> &'a str {
+        self.reason_phrase
+    }
+
+    /// The response body, decoded according to `Content-Length` /
+    /// `Transfer-Encoding: chunked` framing.
+    pub fn body(&self) -> &MessageBody<'a> {
+        &self.body
+    }
+}
+
+/// A trait for the response parser states.
+///
+/// *This trait is sealed.*
+pub trait ResponseParserState: private::SealedResponseParserState {}
+
+impl<S> ResponseParserState for StatusLine<S> {}
+impl ResponseParserState for Header {}
+impl ResponseParserState for Body {}
+
+/// The response parser, the counterpart of [`crate::parser::HttpRequestParser`].
+#[derive(Debug)]
+pub struct HttpResponseParser<'a, S>
+where
+    S: ResponseParserState,
+{
+    packet: &'a str,
+    response: Response<'a>,
+    config: ParserConfig,
+    state: S,
+}
+
+impl<'a, T> HttpResponseParser<'a, T>
+where
+    T: ResponseParserState,
+{
+    /// Re-attach a longer buffer to a parser that previously came back as
+    /// [`ParseStatus::Partial`], so parsing can resume where it left off.
+    ///
+    /// `packet` must start at the same position as the bytes already held by
+    /// this parser -- passing unrelated data produces nonsense rather than an
+    /// error. Use [`Self::remaining`] to read that position back out before
+    /// growing the buffer, since a state can consume several lines (e.g.
+    /// several header fields) before returning `Partial`, shifting it past
+    /// where the caller last resumed from.
+    pub fn resume(self, packet: &'a str) -> Self {
+        Self { packet, ..self }
+    }
+
+    /// The unconsumed tail of the buffer this parser is currently positioned
+    /// at, i.e. the exact bytes a subsequent [`Self::resume`] call needs its
+    /// `packet` argument to start with.
+    pub fn remaining(&self) -> &'a str {
+        self.packet
+    }
+}
+
+/// The `StatusLine`, the parser starting state.
+///
+/// It is defined in RFC 2616 as follows:
+/// ```text
+/// Status-Line = HTTP-Version SP Status-Code SP Reason-Phrase CRLF
+/// ```
+#[derive(Debug)]
+pub struct StatusLine<S> {
+    state: S,
+}
+
+impl<'a, S> HttpResponseParser<'a, StatusLine<S>> {
+    pub fn start(packet: &'a str, config: ParserConfig) -> HttpResponseParser<'a, StatusLine<Version>> {
+        HttpResponseParser {
+            packet,
+            response: Response::new(),
+            config,
+            state: StatusLine { state: Version },
+        }
+    }
+}
+
+type StatusLineParser<'a, S> = HttpResponseParser<'a, StatusLine<S>>;
+
+#[derive(Debug)]
+pub struct Version;
+
+impl<'a> Parse for StatusLineParser<'a, Version> {
+    type NextState = Result<ParseStatus<StatusLineParser<'a, StatusCode>, Self>>;
+
+    fn parse(mut self) -> Self::NextState {
+        let (version, rest) = match try_parse_until_space(self.packet) {
+            Some(parts) => parts,
+            None => return Ok(ParseStatus::Partial(self)),
+        };
+        if !is_valid_version(version) {
+            return Err(ParsingError::InvalidVersion(version.to_string()));
+        }
+        self.response.http_version = version;
+        self.packet = skip_spaces(rest);
+        Ok(ParseStatus::Complete(HttpResponseParser {
+            packet: self.packet,
+            response: self.response,
+            config: self.config,
+            state: StatusLine { state: StatusCode },
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct StatusCode;
+
+impl<'a> Parse for StatusLineParser<'a, StatusCode> {
+    type NextState = Result<ParseStatus<StatusLineParser<'a, ReasonPhrase>, Self>>;
+
+    fn parse(mut self) -> Self::NextState {
+        let (code, rest) = match try_parse_until_space(self.packet) {
+            Some(parts) => parts,
+            None => return Ok(ParseStatus::Partial(self)),
+        };
+        let status_code = code
+            .parse::<u16>()
+            .ok()
+            .filter(|code| (100..=599).contains(code))
+            .ok_or_else(|| ParsingError::InvalidStatusCode(code.to_string()))?;
+        self.response.status_code = status_code;
+        self.packet = skip_spaces(rest);
+        Ok(ParseStatus::Complete(HttpResponseParser {
+            packet: self.packet,
+            response: self.response,
+            config: self.config,
+            state: StatusLine {
+                state: ReasonPhrase,
+            },
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct ReasonPhrase;
+
+impl<'a> Parse for StatusLineParser<'a, ReasonPhrase> {
+    type NextState = Result<ParseStatus<HttpResponseParser<'a, Header>, Self>>;
+
+    fn parse(mut self) -> Self::NextState {
+        let (reason_phrase, rest) = match try_parse_until_crlf(self.packet) {
+            Some(parts) => parts,
+            None => return Ok(ParseStatus::Partial(self)),
+        };
+        self.response.reason_phrase = reason_phrase;
+        self.packet = rest;
+        Ok(ParseStatus::Complete(HttpResponseParser {
+            packet: self.packet,
+            response: self.response,
+            config: self.config,
+            state: Header::new(),
+        }))
+    }
+}
+
+impl<'a> HttpResponseParser<'a, Header> {
+    /// Attempt to parse a single `name: value CRLF` header line.
+    ///
+    /// Returns `Ok(true)` if a full line was consumed, `Ok(false)` if the
+    /// buffer does not yet hold a full line (the caller should wait for more
+    /// bytes before retrying), or `Err` if the line is malformed.
+    fn try_parse_line(&mut self) -> Result<bool> {
+        match try_parse_header_line(self.packet, &self.config)? {
+            Some((key, value, rest)) => {
+                self.packet = rest;
+                self.response.header.push(key, value);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+impl<'a> Parse for HttpResponseParser<'a, Header> {
+    type NextState = Result<ParseStatus<HttpResponseParser<'a, Body>, Self>>;
+
+    fn parse(mut self) -> Self::NextState {
+        loop {
+            let bytes = self.packet.as_bytes();
+            if bytes.len() < 2 {
+                return Ok(ParseStatus::Partial(self));
+            }
+            if is_crlf(&[bytes[0], bytes[1]]) {
+                self.packet = &self.packet[2..];
+                return Ok(ParseStatus::Complete(HttpResponseParser {
+                    packet: self.packet,
+                    response: self.response,
+                    config: self.config,
+                    state: Body,
+                }));
+            }
+            if self.state.header_count >= self.config.max_headers {
+                return Err(ParsingError::TooManyHeaders);
+            }
+            if !self.try_parse_line()? {
+                return Ok(ParseStatus::Partial(self));
+            }
+            self.state.header_count += 1;
+        }
+    }
+}
+
+impl<'a> Parse for HttpResponseParser<'a, Body> {
+    /// `(Response<'a>, &'a str)`: the parsed response, plus whatever bytes
+    /// follow it in the buffer -- the start of a pipelined response, or
+    /// empty if none remain. Feed the remainder into a fresh
+    /// [`HttpResponseParser::start`] to parse it.
+    type NextState = Result<ParseStatus<(Response<'a>, &'a str), Self>>;
+
+    fn parse(mut self) -> Self::NextState {
+        match frame_body(&self.response.header, self.packet)? {
+            ParseStatus::Complete((body, rest)) => {
+                self.response.body = body;
+                Ok(ParseStatus::Complete((self.response, rest)))
+            }
+            ParseStatus::Partial(()) => Ok(ParseStatus::Partial(self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_header_resumes_from_the_offset_remaining_reports() {
+        // Mirrors the request-side test: the header section is split
+        // mid-line, so a socket caller must rely on `remaining()` to know
+        // exactly which bytes to re-send via `resume()`.
+        let packet = "HTTP/1.1 404 Not Found\r\nContent-Ty";
+        let parser = HttpResponseParser::<StatusLine<Version>>::start(packet, ParserConfig::default());
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected version to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected status code to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected reason phrase to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(_) => panic!("expected headers to be partial"),
+            ParseStatus::Partial(parser) => parser,
+        };
+        assert_eq!(parser.remaining(), "Content-Ty");
+
+        let parser = parser.resume("Content-Type: text/plain\r\n\r\nbody");
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected headers to complete after resume"),
+        };
+        let (response, rest) = match parser.parse().unwrap() {
+            ParseStatus::Complete(outcome) => outcome,
+            ParseStatus::Partial(_) => panic!("expected body to be complete"),
+        };
+        assert_eq!(response.headers().get("Content-Type"), Some("text/plain"));
+        assert!(matches!(response.body(), MessageBody::Borrowed("body")));
+        assert_eq!(rest, "");
+    }
+
+    #[test]
+    fn body_parse_applies_content_length_framing_and_returns_pipelined_bytes() {
+        let packet = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhelloHTTP/1.1 404 Not Found\r\n\r\n";
+        let parser = HttpResponseParser::<StatusLine<Version>>::start(packet, ParserConfig::default());
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected version to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected status code to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected reason phrase to be complete"),
+        };
+        let parser = match parser.parse().unwrap() {
+            ParseStatus::Complete(next) => next,
+            ParseStatus::Partial(_) => panic!("expected headers to be complete"),
+        };
+        let (response, rest) = match parser.parse().unwrap() {
+            ParseStatus::Complete(outcome) => outcome,
+            ParseStatus::Partial(_) => panic!("expected body to be complete"),
+        };
+        assert!(matches!(response.body(), MessageBody::Borrowed("hello")));
+        assert_eq!(rest, "HTTP/1.1 404 Not Found\r\n\r\n");
+    }
+}